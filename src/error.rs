@@ -0,0 +1,72 @@
+use std::fmt;
+
+use libc;
+
+use crate::ffi;
+
+/// An error returned by one of libao's operations.
+///
+/// Each variant corresponds to one of libao's documented error codes, set via
+/// `errno` by `ao_open_live`, `ao_open_file`, or `ao_driver_id` upon failure.
+/// Codes libao hasn't documented (or a future libao adds) fall back to
+/// `Unknown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// No driver corresponds to the given driver id or name.
+    NoDriver,
+    /// A file-only operation was attempted on a driver that is not a file driver.
+    NotFile,
+    /// A live-only operation was attempted on a driver that is not a live driver.
+    NotLive,
+    /// One of the settings passed in the option list was invalid for this driver.
+    BadOption,
+    /// The device could not be opened for playback.
+    OpenDevice,
+    /// The output file could not be opened.
+    OpenFile,
+    /// The output file already exists and `overwrite` was not set.
+    FileExists,
+    /// The driver does not support the requested sample format.
+    BadFormat,
+    /// Unspecified failure.
+    Fail,
+    /// An errno value libao returned that isn't one of the documented codes above.
+    Unknown(i32),
+}
+
+impl Error {
+    /// Builds an Error from the current value of errno.
+    pub fn from_errno() -> Self {
+        match unsafe { *libc::__errno_location() } {
+            ffi::AO_ENODRIVER => Error::NoDriver,
+            ffi::AO_ENOTFILE => Error::NotFile,
+            ffi::AO_ENOTLIVE => Error::NotLive,
+            ffi::AO_EBADOPTION => Error::BadOption,
+            ffi::AO_EOPENDEVICE => Error::OpenDevice,
+            ffi::AO_EOPENFILE => Error::OpenFile,
+            ffi::AO_EFILEEXISTS => Error::FileExists,
+            ffi::AO_EBADFORMAT => Error::BadFormat,
+            ffi::AO_EFAIL => Error::Fail,
+            errno => Error::Unknown(errno),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NoDriver => write!(f, "no such driver"),
+            Error::NotFile => write!(f, "this driver is not a file driver"),
+            Error::NotLive => write!(f, "this driver is not a live driver"),
+            Error::BadOption => write!(f, "a bad option was passed to the driver"),
+            Error::OpenDevice => write!(f, "the device could not be opened"),
+            Error::OpenFile => write!(f, "the output file could not be opened"),
+            Error::FileExists => write!(f, "the output file already exists"),
+            Error::BadFormat => write!(f, "the driver does not support the given format"),
+            Error::Fail => write!(f, "an unspecified failure occurred"),
+            Error::Unknown(errno) => write!(f, "unknown ao error (errno {})", errno),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
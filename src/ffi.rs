@@ -0,0 +1,90 @@
+//! Raw FFI bindings to libao, hand-written against `ao/ao.h` since no
+//! `ao-sys` crate is vendored here.
+
+use libc::{c_char, c_int};
+
+/// Error codes libao sets via `errno` before returning a null/negative
+/// result from `ao_open_live`, `ao_open_file`, or `ao_driver_id`.
+pub const AO_ENODRIVER: c_int = 1;
+pub const AO_ENOTFILE: c_int = 2;
+pub const AO_ENOTLIVE: c_int = 3;
+pub const AO_EBADOPTION: c_int = 4;
+pub const AO_EOPENDEVICE: c_int = 5;
+pub const AO_EOPENFILE: c_int = 6;
+pub const AO_EFILEEXISTS: c_int = 7;
+pub const AO_EBADFORMAT: c_int = 8;
+pub const AO_EFAIL: c_int = 100;
+
+#[repr(C)]
+pub struct AoDevice {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct AoOption {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct AoFormat {
+    pub bits: c_int,
+    pub rate: c_int,
+    pub channels: c_int,
+    pub byte_format: c_int,
+    pub matrix: *mut c_char,
+}
+
+/// `type` field of `AoInfo`, identifying whether a driver renders to a live
+/// output or to a file.
+#[allow(dead_code)]
+pub const AO_TYPE_LIVE: c_int = 1;
+pub const AO_TYPE_FILE: c_int = 2;
+
+#[repr(C)]
+pub struct AoInfo {
+    pub type_: c_int,
+    pub name: *mut c_char,
+    pub short_name: *mut c_char,
+    pub author: *mut c_char,
+    pub comment: *mut c_char,
+    pub preferred_byte_format: c_int,
+    pub priority: c_int,
+    pub options: *mut *mut c_char,
+    pub option_count: c_int,
+}
+
+#[link(name = "ao")]
+extern "C" {
+    pub fn ao_initialize();
+    pub fn ao_shutdown();
+
+    pub fn ao_default_driver_id() -> c_int;
+    pub fn ao_driver_id(short_name: *const c_char) -> c_int;
+
+    pub fn ao_open_live(
+        driver_id: c_int,
+        format: *const AoFormat,
+        option: *const AoOption,
+    ) -> *mut AoDevice;
+
+    pub fn ao_open_file(
+        driver_id: c_int,
+        filename: *const c_char,
+        overwrite: c_int,
+        format: *const AoFormat,
+        option: *const AoOption,
+    ) -> *mut AoDevice;
+
+    pub fn ao_driver_info(driver_id: c_int) -> *mut AoInfo;
+    pub fn ao_driver_info_list(driver_count: *mut c_int) -> *mut *mut AoInfo;
+
+    pub fn ao_play(device: &AoDevice, output_samples: *const i8, num_bytes: u32) -> c_int;
+    pub fn ao_close(device: &mut AoDevice) -> c_int;
+
+    pub fn ao_append_option(
+        options: *mut *mut AoOption,
+        key: *const c_char,
+        value: *const c_char,
+    ) -> c_int;
+    pub fn ao_free_options(options: *mut AoOption);
+}
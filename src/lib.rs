@@ -7,8 +7,8 @@ mod ffi;
 
 use error::Error;
 
-use libc::c_int;
-use std::ffi::CString;
+use libc::{c_char, c_int};
+use std::ffi::{CStr, CString};
 use std::ptr;
 use std::ptr::NonNull;
 
@@ -61,6 +61,9 @@ impl Driver {
 
     /// Tries to find a driver with the given name.
     ///
+    /// This also resolves file drivers (e.g. `"wav"`, `"au"`, `"raw"`), unlike
+    /// `Driver::new`, which only ever returns the default live driver.
+    ///
     /// # Panics
     /// If the given name contains inner zero bytes.
     pub fn with_name(short_name: &str) -> Result<Self, Error> {
@@ -77,11 +80,96 @@ impl Driver {
     pub fn driver_id(&self) -> i32 {
         self.driver_id
     }
+
+    /// Returns metadata about this driver, as reported by libao.
+    pub fn info(&self) -> Result<DriverInfo, Error> {
+        unsafe {
+            let info = ffi::ao_driver_info(self.driver_id);
+            match info.as_ref() {
+                Some(info) => Ok(DriverInfo::from_ao_info(info)),
+                None => Err(Error::from_errno()),
+            }
+        }
+    }
+
+    /// Lists every driver libao knows about, paired with its metadata.
+    pub fn list() -> Vec<(Driver, DriverInfo)> {
+        unsafe {
+            let mut count: c_int = 0;
+            let infos = ffi::ao_driver_info_list(&mut count);
+            (0..count as isize)
+                .filter_map(|i| {
+                    let info = *infos.offset(i);
+                    info.as_ref().map(|info| {
+                        let driver = Driver { driver_id: i as i32 };
+                        (driver, DriverInfo::from_ao_info(info))
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+/// Whether a driver renders to a live output or to a file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DriverType {
+    Live,
+    File,
+}
+
+/// Metadata about a driver, as reported by libao's `ao_info`.
+#[derive(Clone, Debug)]
+pub struct DriverInfo {
+    pub driver_type: DriverType,
+    pub name: String,
+    pub short_name: String,
+    pub author: String,
+    pub comment: String,
+    /// The byte format libao prefers for this driver; building a `Format`
+    /// with this avoids libao's internal byte-swapping.
+    pub preferred_byte_format: ByteFormat,
+    pub options: Vec<String>,
+}
+
+impl DriverInfo {
+    /// Copies the given `ao_info` into an owned `DriverInfo`, since libao
+    /// owns the memory behind the pointers it hands back.
+    unsafe fn from_ao_info(info: &ffi::AoInfo) -> Self {
+        let driver_type = if info.type_ == ffi::AO_TYPE_FILE {
+            DriverType::File
+        } else {
+            DriverType::Live
+        };
+        let preferred_byte_format = match info.preferred_byte_format {
+            2 => ByteFormat::Big,
+            4 => ByteFormat::Native,
+            _ => ByteFormat::Little,
+        };
+        let options = (0..info.option_count as isize)
+            .map(|i| cstr_to_string(*info.options.offset(i)))
+            .collect();
+
+        DriverInfo {
+            driver_type,
+            name: cstr_to_string(info.name),
+            short_name: cstr_to_string(info.short_name),
+            author: cstr_to_string(info.author),
+            comment: cstr_to_string(info.comment),
+            preferred_byte_format,
+            options,
+        }
+    }
+}
+
+/// Copies a non-null, NUL-terminated C string into an owned `String`.
+unsafe fn cstr_to_string(ptr: *const libc::c_char) -> String {
+    CStr::from_ptr(ptr).to_string_lossy().into_owned()
 }
 
 /// Ao device.
 pub struct Device {
     device: NonNull<ffi::AoDevice>,
+    format: Format,
 }
 
 impl Device {
@@ -95,8 +183,8 @@ impl Device {
             Some(settings) => settings.as_ao_option(),
             None => ptr::null(),
         };
-        let ao_device =
-            unsafe { ffi::ao_open_live(driver.driver_id(), &format.to_ao_format(), options) };
+        let (ao_format, _matrix) = format.to_ao_format();
+        let ao_device = unsafe { ffi::ao_open_live(driver.driver_id(), &ao_format, options) };
 
         // unique new does a null-ptr check now
         let ao_device = match NonNull::new(ao_device) {
@@ -104,15 +192,84 @@ impl Device {
             None => return Err(Error::from_errno()),
         };
 
-        Ok(Device { device: ao_device })
+        Ok(Device {
+            device: ao_device,
+            format: format.clone(),
+        })
+    }
+
+    /// Creates a new file device using the given driver, format, output path,
+    /// and settings. `driver` must be a file driver (see `Driver::with_name`);
+    /// the returned `Device` renders PCM to `path` instead of to a live
+    /// output, but otherwise behaves the same as one from `Device::new`.
+    ///
+    /// If `overwrite` is `false` and `path` already exists, this returns
+    /// `Err` with the underlying `AO_EFILEEXISTS` failure.
+    ///
+    /// # Panics
+    /// If `path` contains inner zero bytes.
+    pub fn new_file(
+        driver: &Driver,
+        format: &Format,
+        path: &str,
+        overwrite: bool,
+        settings: Option<&Settings>,
+    ) -> Result<Self, Error> {
+        let options = match settings {
+            Some(settings) => settings.as_ao_option(),
+            None => ptr::null(),
+        };
+        let path = CString::new(path).unwrap();
+        let (ao_format, _matrix) = format.to_ao_format();
+        let ao_device = unsafe {
+            ffi::ao_open_file(
+                driver.driver_id(),
+                path.as_ptr(),
+                overwrite as c_int,
+                &ao_format,
+                options,
+            )
+        };
+
+        let ao_device = match NonNull::new(ao_device) {
+            Some(udev) => udev,
+            None => return Err(Error::from_errno()),
+        };
+
+        Ok(Device {
+            device: ao_device,
+            format: format.clone(),
+        })
     }
 
-    /// Plays the given PCM data using the specified format.
+    /// Plays the given pre-packed PCM data using the specified format. This
+    /// is the zero-copy fast path; callers are responsible for packing
+    /// `buffer` themselves according to the `Format` the device was opened
+    /// with. See `play_samples` for a safer, typed alternative.
     pub fn play(&self, buffer: &[i8]) {
         unsafe {
             ffi::ao_play(self.device.as_ref(), buffer.as_ptr(), buffer.len() as u32);
         }
     }
+
+    /// Packs and plays the given samples, honoring the `bits` and
+    /// `byte_format` of the `Format` this device was opened with.
+    pub fn play_samples<S: Sample>(&self, samples: &[S]) {
+        let bytes_per_sample = (self.format.bits / 8) as usize;
+        let byte_format = self.format.byte_format.resolve_native();
+
+        let mut buffer = Vec::with_capacity(samples.len() * bytes_per_sample);
+        for &sample in samples {
+            let bytes = sample.to_i32().to_le_bytes();
+            let bytes = &bytes[..bytes_per_sample];
+            match byte_format {
+                ByteFormat::Big => buffer.extend(bytes.iter().rev().map(|&b| b as i8)),
+                _ => buffer.extend(bytes.iter().map(|&b| b as i8)),
+            }
+        }
+
+        self.play(&buffer);
+    }
 }
 
 impl Drop for Device {
@@ -172,12 +329,15 @@ impl Drop for Settings {
 }
 
 /// Ao sample format.
+#[derive(Clone)]
 pub struct Format {
     pub bits: u32,
     pub rate: u32,
     pub channels: u32,
     pub byte_format: ByteFormat,
-    // TODO: Implement macros for creating channel formats (mono, stereo, etc).
+    /// A comma-separated list of channel labels (e.g. `"L,R"`, `"M"`), matched
+    /// up against `channels`. See `Format::mono`/`stereo`/`surround_51` for
+    /// convenience constructors that keep the two in sync.
     pub channel_format: Option<String>,
 }
 
@@ -187,15 +347,56 @@ impl Format {
         Format::default()
     }
 
-    /// Returns a new AoFormat without consuming self.
-    pub fn to_ao_format(&self) -> ffi::AoFormat {
-        ffi::AoFormat {
+    /// Creates a mono format using the `"M"` channel matrix.
+    pub fn mono() -> Self {
+        Format {
+            channels: 1,
+            channel_format: Some("M".to_string()),
+            ..Format::default()
+        }
+    }
+
+    /// Creates a stereo format using the `"L,R"` channel matrix.
+    pub fn stereo() -> Self {
+        Format {
+            channels: 2,
+            channel_format: Some("L,R".to_string()),
+            ..Format::default()
+        }
+    }
+
+    /// Creates a 5.1 surround format using the `"L,R,C,LFE,BL,BR"` channel matrix.
+    pub fn surround_51() -> Self {
+        Format {
+            channels: 6,
+            channel_format: Some("L,R,C,LFE,BL,BR".to_string()),
+            ..Format::default()
+        }
+    }
+
+    /// Returns a new AoFormat without consuming self, along with the owned
+    /// `CString` backing its channel matrix (if any). libao reads the matrix
+    /// pointer during `ao_open_live`/`ao_open_file`, so callers must keep the
+    /// returned `CString` alive for the duration of that call.
+    pub fn to_ao_format(&self) -> (ffi::AoFormat, Option<CString>) {
+        let matrix = self
+            .channel_format
+            .as_ref()
+            .map(|channels| CString::new(channels.as_str()).unwrap());
+        let matrix_ptr = matrix
+            .as_ref()
+            .map(|matrix| matrix.as_ptr() as *mut c_char)
+            .unwrap_or(ptr::null_mut());
+
+        let ao_format = ffi::AoFormat {
             bits: self.bits as c_int,
             rate: self.rate as c_int,
             channels: self.channels as c_int,
             byte_format: self.byte_format as c_int,
-            matrix: ptr::null_mut(),
-        }
+            matrix: matrix_ptr,
+        };
+
+        (ao_format, matrix)
     }
 }
 
@@ -212,9 +413,62 @@ impl Default for Format {
 }
 
 /// Byte format, can either by little-endian, bit-endian, or native (inherits from system).
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub enum ByteFormat {
     Little = 1,
     Big = 2,
     Native = 4,
 }
+
+impl ByteFormat {
+    /// Resolves `Native` to `Little` or `Big` based on the host's endianness.
+    fn resolve_native(self) -> Self {
+        match self {
+            ByteFormat::Native => {
+                if cfg!(target_endian = "big") {
+                    ByteFormat::Big
+                } else {
+                    ByteFormat::Little
+                }
+            }
+            other => other,
+        }
+    }
+}
+
+/// A PCM sample that `Device::play_samples` knows how to pack into bytes.
+pub trait Sample: Copy {
+    /// Widens this sample to `i32` so `play_samples` can truncate it to the
+    /// device's configured bit depth.
+    fn to_i32(self) -> i32;
+}
+
+impl Sample for i8 {
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+impl Sample for u8 {
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+impl Sample for i16 {
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+impl Sample for u16 {
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+impl Sample for i32 {
+    fn to_i32(self) -> i32 {
+        self
+    }
+}